@@ -78,7 +78,8 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
@@ -93,6 +94,7 @@ struct ErrorKindArgs {
     code: LitInt,
     _comma2: Token![,],
     description: LitStr,
+    exit_code: Option<LitInt>,
 }
 
 impl Parse for ErrorKindArgs {
@@ -109,6 +111,13 @@ impl Parse for ErrorKindArgs {
         let _comma2: Token![,] = content.parse()?;
         let description: LitStr = content.parse()?;
 
+        let exit_code = if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+            Some(content.parse()?)
+        } else {
+            None
+        };
+
         Ok(ErrorKindArgs {
             const_name,
             _eq,
@@ -118,6 +127,7 @@ impl Parse for ErrorKindArgs {
             code,
             _comma2,
             description,
+            exit_code,
         })
     }
 }
@@ -138,6 +148,12 @@ impl Parse for ErrorKindArgsList {
 /// of structured error kinds by allowing developers to declare them using a concise syntax. It takes a list of error definitions and expands
 /// them into properly structured `oxiderr::ErrorKind` constants.
 ///
+/// A fifth, optional tuple element sets the process exit code a generated error carrying this
+/// kind should terminate with (see `exit_code()` and `std::process::Termination` on the structs
+/// emitted by `define_errors`). When omitted, the exit code defaults to `1` for client-side
+/// codes (0-499) and `70` (`EX_SOFTWARE`) for server-side codes, mirroring Mercurial's
+/// `detailed_exit_code`.
+///
 /// # Usage Example
 ///
 /// ## Macro Input
@@ -145,7 +161,7 @@ impl Parse for ErrorKindArgsList {
 /// ```rust
 /// define_kinds! {
 ///     FileNotFound = ("File not found", 404, "The requested file could not be located"),
-///     PermissionDenied = ("Permission denied", 403, "The user lacks the necessary permissions")
+///     PermissionDenied = ("Permission denied", 403, "The user lacks the necessary permissions", 77)
 /// }
 /// ```
 /// ## Macro Expansion (Generated Code)
@@ -158,6 +174,13 @@ impl Parse for ErrorKindArgsList {
 ///     404,
 ///     "The requested file could not be located"
 /// );
+/// #[allow(non_snake_case)]
+/// pub fn __oxiderr_exit_code_FileNotFound() -> i32 {
+///     match FileNotFound.code() {
+///         0..=499 => 1,
+///         _ => 70,
+///     }
+/// }
 ///
 /// #[allow(non_upper_case_globals)]
 /// pub const PermissionDenied: oxiderr::ErrorKind = oxiderr::ErrorKind(
@@ -166,6 +189,10 @@ impl Parse for ErrorKindArgsList {
 ///     403,
 ///     "The user lacks the necessary permissions"
 /// );
+/// #[allow(non_snake_case)]
+/// pub fn __oxiderr_exit_code_PermissionDenied() -> i32 {
+///     77
+/// }
 /// ```
 #[proc_macro]
 pub fn define_kinds(input: TokenStream) -> TokenStream {
@@ -176,10 +203,26 @@ pub fn define_kinds(input: TokenStream) -> TokenStream {
         let message = &args.message;
         let code = &args.code;
         let description = &args.description;
+        let exit_code_fn = format_ident!("__oxiderr_exit_code_{}", const_name);
+
+        let exit_code_body = match &args.exit_code {
+            Some(exit_code) => quote! { #exit_code },
+            None => quote! {
+                match #const_name.code() {
+                    0..=499 => 1,
+                    _ => 70,
+                }
+            },
+        };
 
         quote! {
             #[allow(non_upper_case_globals)]
             pub const #const_name: oxiderr::ErrorKind = oxiderr::ErrorKind(stringify!(#const_name), #message, #code, #description);
+
+            #[allow(non_snake_case)]
+            pub fn #exit_code_fn() -> i32 {
+                #exit_code_body
+            }
         }
     });
 
@@ -188,9 +231,14 @@ pub fn define_kinds(input: TokenStream) -> TokenStream {
     })
 }
 
+mod kw {
+    syn::custom_keyword!(from);
+}
+
 struct ErrorDefinition {
     name: Ident,
     kind: Type,
+    from_types: Vec<Type>,
 }
 
 struct ErrorDefinitions {
@@ -205,10 +253,20 @@ impl Parse for ErrorDefinitions {
             let name: Ident = input.parse()?;
             input.parse::<Token![=]>()?;
             let kind: Type = input.parse()?;
+
+            let mut from_types = Vec::new();
+            if input.peek(kw::from) {
+                input.parse::<kw::from>()?;
+                let content;
+                parenthesized!(content in input);
+                let types: Punctuated<Type, Comma> = Punctuated::parse_terminated(&content)?;
+                from_types.extend(types);
+            }
+
             if input.peek(Token![,]) {
                 input.parse::<Token![,]>()?;
             }
-            definitions.push(ErrorDefinition { name, kind });
+            definitions.push(ErrorDefinition { name, kind, from_types });
         }
 
         Ok(ErrorDefinitions { definitions })
@@ -223,7 +281,18 @@ impl Parse for ErrorDefinitions {
 /// * Implements `oxiderr::AsError` for interoperability with `oxiderr::ErrorKind`.
 /// * Provides methods for setting error messages and details.
 /// * Supports conversion from `oxiderr::Error`.
-
+/// * Captures a lazily-resolved backtrace at construction time, exposed through `backtrace()`.
+/// * Tracks an optional cause via `caused_by()`, surfaced through `source()` and `chain()`.
+/// * Optionally emits `From<T>` conversions for a `from(...)` list of source error types, so
+///   the generated error can be produced with `?`.
+/// * Provides `with_context`/`at_path` to record path/operation/os-error context in `details`.
+/// * Exposes `exit_code()` and implements `std::process::Termination`, so a CLI can
+///   `return err` from `main` with the right shell exit status for that error's kind.
+///
+/// The backtrace/chain support modules referenced below (`__oxiderr_backtrace`, `__oxiderr_chain`)
+/// are actually named with a numeric suffix unique to each `define_errors!` invocation
+/// (e.g. `__oxiderr_backtrace_0`), so that two invocations in the same module don't collide.
+///
 /// # Usage Example
 ///
 /// ## Macro Input
@@ -231,17 +300,32 @@ impl Parse for ErrorDefinitions {
 /// ```rust
 /// define_errors! {
 ///     NotFoundError = FileNotFound,
-///     UnauthorizedError = PermissionDenied
+///     UnauthorizedError = PermissionDenied,
+///     IoFailure = IoError from(std::io::Error, std::net::AddrParseError)
 /// }
 /// ```
 /// ## Macro Expansion (Generated Code for NotFoundError)
 ///
 /// ```rust
-/// #[derive(Debug, Clone)]
+/// #[derive(Debug)]
 /// pub struct NotFoundError {
 ///     class: String,
 ///     message: String,
 ///     details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+///     backtrace: __oxiderr_backtrace::InternalBacktrace,
+///     source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+/// }
+///
+/// impl Clone for NotFoundError {
+///     fn clone(&self) -> Self {
+///         Self {
+///             class: self.class.clone(),
+///             message: self.message.clone(),
+///             details: self.details.clone(),
+///             backtrace: self.backtrace.clone(),
+///             source: None,
+///         }
+///     }
 /// }
 ///
 /// impl NotFoundError {
@@ -252,9 +336,24 @@ impl Parse for ErrorDefinitions {
 ///             class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), "NotFoundError"),
 ///             message: Self::kind.description().into(),
 ///             details: None,
+///             backtrace: __oxiderr_backtrace::InternalBacktrace::capture(),
+///             source: None,
 ///         }
 ///     }
 ///
+///     pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+///         self.backtrace.as_backtrace()
+///     }
+///
+///     pub fn caused_by(mut self, err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+///         self.source = Some(err.into());
+///         self
+///     }
+///
+///     pub fn chain(&self) -> __oxiderr_chain::Chain<'_> {
+///         __oxiderr_chain::chain(self)
+///     }
+///
 ///     pub fn set_message(mut self, message: String) -> Self {
 ///         self.message = message;
 ///         self
@@ -265,6 +364,25 @@ impl Parse for ErrorDefinitions {
 ///         self
 ///     }
 ///
+///     pub fn with_context(mut self, operation: &str, path: impl AsRef<std::path::Path>, os_error: Option<i32>) -> Self {
+///         let mut details = self.details.take().unwrap_or_default();
+///         details.insert("operation".to_string(), serde_value::to_value(operation).unwrap());
+///         details.insert("path".to_string(), serde_value::to_value(path.as_ref().display().to_string()).unwrap());
+///         if let Some(code) = os_error {
+///             details.insert("os_error".to_string(), serde_value::to_value(code).unwrap());
+///         }
+///         self.details = Some(details);
+///         self
+///     }
+///
+///     pub fn at_path<P: AsRef<std::path::Path>>(operation: &'static str, path: P) -> impl FnOnce(std::io::Error) -> Self {
+///         move |err: std::io::Error| {
+///             let os_error = err.raw_os_error();
+///             let message = err.to_string();
+///             Self::new().set_message(message).with_context(operation, path, os_error).caused_by(err)
+///         }
+///     }
+///
 ///     pub fn convert(error: oxiderr::Error) -> Self {
 ///         let mut err_clone = error.clone();
 ///         let mut details = error.details.unwrap_or_default();
@@ -275,6 +393,8 @@ impl Parse for ErrorDefinitions {
 ///             class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), "NotFoundError"),
 ///             message: Self::kind.description().into(),
 ///             details: Some(details),
+///             backtrace: __oxiderr_backtrace::InternalBacktrace::capture(),
+///             source: None,
 ///         }
 ///     }
 /// }
@@ -294,11 +414,55 @@ impl Parse for ErrorDefinitions {
 ///     }
 /// }
 ///
-/// impl std::error::Error for NotFoundError {}
+/// impl std::error::Error for NotFoundError {
+///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+///         self.source.as_deref().map(|err| err as &(dyn std::error::Error + 'static))
+///     }
+/// }
 ///
 /// impl std::fmt::Display for NotFoundError {
 ///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-///         write!(f, "[{}] {} ({}): {}", Self::kind.message_id(), "NotFoundError", Self::kind.code(), self.message())
+///         write!(f, "[{}] {} ({}): {}", Self::kind.message_id(), "NotFoundError", Self::kind.code(), self.message())?;
+///         if let Some(cause) = std::error::Error::source(self) {
+///             write!(f, "\ncaused by: {}", cause)?;
+///         }
+///         if let Some(bt) = self.backtrace() {
+///             write!(f, "\n{:?}", bt)?;
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+/// ## Macro Expansion (Additional Code for IoFailure's `from` List)
+///
+/// ```rust
+/// impl From<std::io::Error> for IoFailure {
+///     fn from(src: std::io::Error) -> Self {
+///         let message = src.to_string();
+///         Self::new().set_message(message).caused_by(src)
+///     }
+/// }
+///
+/// impl From<std::net::AddrParseError> for IoFailure {
+///     fn from(src: std::net::AddrParseError) -> Self {
+///         let message = src.to_string();
+///         Self::new().set_message(message).caused_by(src)
+///     }
+/// }
+/// ```
+/// ## Macro Expansion (Exit Code for NotFoundError)
+///
+/// ```rust
+/// impl NotFoundError {
+///     pub fn exit_code(&self) -> i32 {
+///         __oxiderr_exit_code_FileNotFound()
+///     }
+/// }
+///
+/// impl std::process::Termination for NotFoundError {
+///     fn report(self) -> std::process::ExitCode {
+///         eprintln!("{}", self);
+///         std::process::ExitCode::from(self.exit_code() as u8)
 ///     }
 /// }
 /// ```
@@ -306,16 +470,72 @@ impl Parse for ErrorDefinitions {
 pub fn define_errors(input: TokenStream) -> TokenStream {
     let definitions = parse_macro_input!(input as ErrorDefinitions);
 
+    // Counts `define_errors!` invocations within this compilation, so each invocation's
+    // support modules can be given a unique name (see `invocation_id` below) and two
+    // invocations in the same module never collide.
+    static DEFINE_ERRORS_INVOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    let invocation_id = DEFINE_ERRORS_INVOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let backtrace_mod = format_ident!("__oxiderr_backtrace_{}", invocation_id);
+    let chain_mod = format_ident!("__oxiderr_chain_{}", invocation_id);
+
     let generated_structs = definitions.definitions.iter().map(|definition| {
         let name = &definition.name;
         let kind = &definition.kind;
 
+        let from_impls = definition.from_types.iter().map(|from_type| {
+            quote! {
+                impl From<#from_type> for #name {
+                    fn from(src: #from_type) -> Self {
+                        let message = src.to_string();
+                        Self::new().set_message(message).caused_by(src)
+                    }
+                }
+            }
+        });
+
+        let exit_code_fn_path = match kind {
+            Type::Path(type_path) => {
+                let mut path = type_path.path.clone();
+                if let Some(segment) = path.segments.last_mut() {
+                    segment.ident = format_ident!("__oxiderr_exit_code_{}", segment.ident);
+                    segment.arguments = syn::PathArguments::None;
+                }
+                Some(path)
+            }
+            _ => None,
+        };
+        let exit_code_body = match exit_code_fn_path {
+            Some(exit_code_fn_path) => quote! { #exit_code_fn_path() },
+            None => quote! {
+                match Self::kind.code() {
+                    0..=499 => 1,
+                    _ => 70,
+                }
+            },
+        };
+
         quote! {
-            #[derive(Debug, Clone)]
+            #[derive(Debug)]
             pub struct #name {
                 class: String,
                 message: String,
                 details: Option<std::collections::BTreeMap<String, serde_value::Value>>,
+                backtrace: #backtrace_mod::InternalBacktrace,
+                source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+            }
+
+            // `source` holds a `dyn Error`, which isn't `Clone`, so this can't be derived;
+            // the cause is dropped when cloning rather than cloning everything else.
+            impl Clone for #name {
+                fn clone(&self) -> Self {
+                    Self {
+                        class: self.class.clone(),
+                        message: self.message.clone(),
+                        details: self.details.clone(),
+                        backtrace: self.backtrace.clone(),
+                        source: None,
+                    }
+                }
             }
 
             impl #name {
@@ -325,6 +545,8 @@ pub fn define_errors(input: TokenStream) -> TokenStream {
                         class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), stringify!(#name)),
                         message: Self::kind.description().into(),
                         details: None,
+                        backtrace: #backtrace_mod::InternalBacktrace::capture(),
+                        source: None,
                     }
                 }
                 pub fn set_message(mut self, message: String) -> Self {
@@ -335,6 +557,54 @@ pub fn define_errors(input: TokenStream) -> TokenStream {
                     self.details = Some(details);
                     self
                 }
+
+                /// Records filesystem/operation context into `details`, merging it with
+                /// whatever is already there rather than replacing it (unlike `set_details`).
+                pub fn with_context(mut self, operation: &str, path: impl AsRef<std::path::Path>, os_error: Option<i32>) -> Self {
+                    let mut details = self.details.take().unwrap_or_default();
+                    details.insert("operation".to_string(), serde_value::to_value(operation).unwrap());
+                    details.insert("path".to_string(), serde_value::to_value(path.as_ref().display().to_string()).unwrap());
+                    if let Some(code) = os_error {
+                        details.insert("os_error".to_string(), serde_value::to_value(code).unwrap());
+                    }
+                    self.details = Some(details);
+                    self
+                }
+
+                /// A `map_err`-friendly helper mirroring fs-err: wraps the path and operation
+                /// that produced a `std::io::Error` into `details`, so the resulting error is
+                /// self-describing without the caller building the map by hand.
+                ///
+                /// ```ignore
+                /// std::fs::File::open(&path).map_err(FileNotExists::at_path("open", &path))?;
+                /// ```
+                pub fn at_path<P: AsRef<std::path::Path>>(operation: &'static str, path: P) -> impl FnOnce(std::io::Error) -> Self {
+                    move |err: std::io::Error| {
+                        let os_error = err.raw_os_error();
+                        let message = err.to_string();
+                        Self::new().set_message(message).with_context(operation, path, os_error).caused_by(err)
+                    }
+                }
+
+                /// Attaches the underlying cause of this error, made available through
+                /// `std::error::Error::source` and woven into `Display` as `caused by:` lines.
+                pub fn caused_by(mut self, err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+                    self.source = Some(err.into());
+                    self
+                }
+
+                /// Walks this error and its chain of causes, innermost last.
+                pub fn chain(&self) -> #chain_mod::Chain<'_> {
+                    #chain_mod::chain(self)
+                }
+
+                /// The process exit code this error should terminate with, set via
+                /// `define_kinds`'s optional fifth tuple element, or defaulted from whether
+                /// `Self::kind.code()` is a client (4xx) or server (5xx) code.
+                pub fn exit_code(&self) -> i32 {
+                    #exit_code_body
+                }
+
                 pub fn convert(error: oxiderr::Error) -> Self {
                     let mut err_clone = error.clone();
                     let mut details = error.details.unwrap_or_default();
@@ -344,8 +614,20 @@ pub fn define_errors(input: TokenStream) -> TokenStream {
                         class: format!("{}::{}::{}", Self::kind.side(), Self::kind.name(), stringify!(#name)),
                         message: Self::kind.description().into(),
                         details: Some(details),
+                        backtrace: #backtrace_mod::InternalBacktrace::capture(),
+                        source: None,
                     }
                 }
+
+                /// Returns the backtrace captured when this error was created, if backtrace
+                /// capture was enabled (see `InternalBacktrace::capture`).
+                ///
+                /// Symbol resolution is deferred until the backtrace is first formatted
+                /// (`Display`/`Debug`), so capturing the error is cheap even when the
+                /// backtrace is never displayed.
+                pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+                    self.backtrace.as_backtrace()
+                }
             }
             impl oxiderr::AsError for #name {
                 fn kind()-> oxiderr::ErrorKind {
@@ -362,17 +644,222 @@ pub fn define_errors(input: TokenStream) -> TokenStream {
                 }
             }
 
-            impl std::error::Error for #name {}
+            impl std::error::Error for #name {
+                fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                    self.source.as_deref().map(|err| err as &(dyn std::error::Error + 'static))
+                }
+            }
 
             impl std::fmt::Display for #name {
                 fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                    write!(f, "[{}] {} ({}): {}", Self::kind.message_id(), stringify!(#name), Self::kind.code(), self.message())
+                    write!(f, "[{}] {} ({}): {}", Self::kind.message_id(), stringify!(#name), Self::kind.code(), self.message())?;
+                    // Print only the immediate cause: if it is itself one of our generated
+                    // errors, its own `Display` already renders the rest of the chain, so
+                    // walking the full `chain()` here would print each ancestor twice.
+                    if let Some(cause) = std::error::Error::source(self) {
+                        write!(f, "\ncaused by: {}", cause)?;
+                    }
+                    if let Some(bt) = self.backtrace() {
+                        write!(f, "\n{:?}", bt)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl std::process::Termination for #name {
+                fn report(self) -> std::process::ExitCode {
+                    eprintln!("{}", self);
+                    std::process::ExitCode::from(self.exit_code() as u8)
                 }
             }
+
+            #(#from_impls)*
         }
     });
 
     TokenStream::from(quote! {
+        #[doc(hidden)]
+        mod #backtrace_mod {
+            use std::sync::atomic::{AtomicUsize, Ordering};
+            use std::sync::Arc;
+
+            const UNCHECKED: usize = 0;
+            const DISABLED: usize = 1;
+            const ENABLED: usize = 2;
+
+            static STATE: AtomicUsize = AtomicUsize::new(UNCHECKED);
+
+            /// Reads `OXIDERR_BACKTRACE`/`RUST_BACKTRACE` once and caches the result, so
+            /// every subsequent call to `InternalBacktrace::capture` is a single atomic load.
+            fn capture_enabled() -> bool {
+                match STATE.load(Ordering::Relaxed) {
+                    DISABLED => false,
+                    ENABLED => true,
+                    _ => {
+                        let enabled = std::env::var("OXIDERR_BACKTRACE")
+                            .or_else(|_| std::env::var("RUST_BACKTRACE"))
+                            .map(|value| value != "0")
+                            .unwrap_or(false);
+                        STATE.store(if enabled { ENABLED } else { DISABLED }, Ordering::Relaxed);
+                        enabled
+                    }
+                }
+            }
+
+            /// A lazily-resolved backtrace, captured at error construction time.
+            ///
+            /// `std::backtrace::Backtrace` already defers symbol resolution to its first
+            /// `Display`/`Debug`, so the only thing this wrapper adds is `Clone` (via `Arc`,
+            /// since `Backtrace` itself isn't `Clone`) and the `Option` used to make capture
+            /// a no-op when disabled.
+            #[derive(Clone, Debug, Default)]
+            pub struct InternalBacktrace(Option<Arc<std::backtrace::Backtrace>>);
+
+            impl InternalBacktrace {
+                /// Captures the current call stack if `RUST_BACKTRACE`/`OXIDERR_BACKTRACE`
+                /// enables it, otherwise returns an empty, zero-cost instance.
+                pub fn capture() -> Self {
+                    if capture_enabled() {
+                        InternalBacktrace(Some(Arc::new(std::backtrace::Backtrace::force_capture())))
+                    } else {
+                        InternalBacktrace(None)
+                    }
+                }
+
+                pub fn as_backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+                    self.0.as_deref()
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        mod #chain_mod {
+            /// Iterates an error and its causes, following `std::error::Error::source`
+            /// the way `chainerror` walks a chain, from the outermost error down.
+            pub struct Chain<'a> {
+                next: Option<&'a (dyn std::error::Error + 'static)>,
+            }
+
+            impl<'a> Iterator for Chain<'a> {
+                type Item = &'a (dyn std::error::Error + 'static);
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let current = self.next.take();
+                    self.next = current.and_then(std::error::Error::source);
+                    current
+                }
+            }
+
+            pub fn chain<'a>(head: &'a (dyn std::error::Error + 'static)) -> Chain<'a> {
+                Chain { next: Some(head) }
+            }
+        }
+
         #(#generated_structs)*
     })
 }
+
+struct IoMapEntry {
+    pattern: IoMapPattern,
+    target: Type,
+}
+
+enum IoMapPattern {
+    Variant(Ident),
+    Wildcard,
+}
+
+impl Parse for IoMapEntry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = if input.peek(Token![_]) {
+            input.parse::<Token![_]>()?;
+            IoMapPattern::Wildcard
+        } else {
+            IoMapPattern::Variant(input.parse()?)
+        };
+        input.parse::<Token![=>]>()?;
+        let target: Type = input.parse()?;
+
+        Ok(IoMapEntry { pattern, target })
+    }
+}
+
+struct IoMapEntries {
+    entries: Punctuated<IoMapEntry, Comma>,
+}
+
+impl Parse for IoMapEntries {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(IoMapEntries {
+            entries: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// The `map_io_error` macro generates a `from_io` dispatcher that routes a `std::io::Error`
+/// to a distinct generated error type based on its `std::io::ErrorKind`, the way Deno's
+/// `op_error.rs` and `fs_extra`'s `ErrorKind` map I/O failures to structured domain errors.
+///
+/// Each arm names a `std::io::ErrorKind` variant (unqualified) and the generated error type
+/// to produce for it; a trailing `_ => Default` arm is required, since `std::io::ErrorKind`
+/// is non-exhaustive. The matched error becomes the new error's message and cause.
+///
+/// # Usage Example
+///
+/// ## Macro Input
+///
+/// ```rust
+/// map_io_error! {
+///     NotFound => FileNotExists,
+///     PermissionDenied => PermissionDenied,
+///     _ => Unexpected,
+/// }
+/// ```
+/// ## Macro Expansion (Generated Code)
+///
+/// ```rust
+/// pub fn from_io(err: std::io::Error) -> oxiderr::Error {
+///     match err.kind() {
+///         std::io::ErrorKind::NotFound => {
+///             let message = err.to_string();
+///             FileNotExists::new().set_message(message).caused_by(err).into()
+///         }
+///         std::io::ErrorKind::PermissionDenied => {
+///             let message = err.to_string();
+///             PermissionDenied::new().set_message(message).caused_by(err).into()
+///         }
+///         _ => {
+///             let message = err.to_string();
+///             Unexpected::new().set_message(message).caused_by(err).into()
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn map_io_error(input: TokenStream) -> TokenStream {
+    let entries = parse_macro_input!(input as IoMapEntries);
+
+    let arms = entries.entries.iter().map(|entry| {
+        let target = &entry.target;
+
+        let pattern = match &entry.pattern {
+            IoMapPattern::Variant(ident) => quote! { std::io::ErrorKind::#ident },
+            IoMapPattern::Wildcard => quote! { _ },
+        };
+
+        quote! {
+            #pattern => {
+                let message = err.to_string();
+                #target::new().set_message(message).caused_by(err).into()
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        pub fn from_io(err: std::io::Error) -> oxiderr::Error {
+            match err.kind() {
+                #(#arms)*
+            }
+        }
+    })
+}
@@ -0,0 +1,19 @@
+use oxiderr::{define_errors, define_kinds, AsError};
+
+define_kinds! {
+    Traced = ("Err-00001", 500, "traced error")
+}
+
+define_errors! {
+    TracedErr = Traced
+}
+
+// Only one test in this file: `InternalBacktrace::capture` caches whether capture is
+// enabled in a process-wide static the first time it's read, so toggling the env var
+// across tests in the same binary would be racy.
+#[test]
+fn backtrace_is_captured_when_enabled() {
+    std::env::set_var("OXIDERR_BACKTRACE", "1");
+    let err = TracedErr::new();
+    assert!(err.backtrace().is_some());
+}
@@ -0,0 +1,66 @@
+use oxiderr::{define_errors, define_kinds, AsError};
+use std::error::Error as _;
+
+define_kinds! {
+    NotFound = ("Err-00001", 404, "not found"),
+    BadInput = ("Err-00002", 400, "bad input"),
+    Unreachable = ("Err-00003", 503, "unreachable", 69)
+}
+
+define_errors! {
+    NotFoundErr = NotFound,
+    BadInputErr = BadInput from(std::io::Error),
+    UnreachableErr = Unreachable
+}
+
+#[test]
+fn exit_code_defaults_from_the_kind_code_class() {
+    assert_eq!(NotFoundErr::new().exit_code(), 1);
+    assert_eq!(BadInputErr::new().exit_code(), 1);
+}
+
+#[test]
+fn exit_code_uses_the_kind_s_explicit_override() {
+    assert_eq!(UnreachableErr::new().exit_code(), 69);
+}
+
+#[test]
+fn from_dispatches_through_caused_by() {
+    let io_err = std::io::Error::other("disk exploded");
+    let err: BadInputErr = io_err.into();
+    assert_eq!(err.message(), "disk exploded");
+    assert!(err.source().is_some());
+}
+
+#[test]
+fn display_prints_each_cause_exactly_once() {
+    // Regression test: Display used to walk the full `chain()`, so a cause that is
+    // itself a generated error (and therefore prints its own source) got its message
+    // printed twice - once by its own Display, once again by the outer loop.
+    let a = NotFoundErr::new().set_message("a failed".into());
+    let b = BadInputErr::new().set_message("b failed".into());
+    let c = NotFoundErr::new().set_message("c failed".into());
+
+    let rendered = format!("{}", a.caused_by(b.caused_by(c)));
+    for message in ["a failed", "b failed", "c failed"] {
+        assert_eq!(rendered.matches(message).count(), 1, "{message} should appear exactly once in: {rendered}");
+    }
+}
+
+#[test]
+fn with_context_merges_into_existing_details() {
+    let err = NotFoundErr::new()
+        .set_details([("attempt".to_string(), serde_value::Value::U8(1))].into_iter().collect())
+        .with_context("open", "/tmp/missing", Some(2));
+    let details = err.details().unwrap();
+    assert_eq!(details.len(), 4);
+}
+
+#[test]
+fn at_path_wraps_an_io_error_with_its_operation_and_path() {
+    let result = std::fs::File::open("/does/not/exist").map_err(NotFoundErr::at_path("open", "/does/not/exist"));
+    let err = result.unwrap_err();
+    let details = err.details().unwrap();
+    assert_eq!(details.get("operation").unwrap().clone(), serde_value::Value::String("open".into()));
+    assert!(err.source().is_some());
+}
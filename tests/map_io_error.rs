@@ -0,0 +1,31 @@
+use oxiderr::{define_errors, define_kinds, map_io_error, AsError};
+
+define_kinds! {
+    Missing = ("Err-00001", 404, "not found"),
+    Forbidden = ("Err-00002", 403, "forbidden"),
+    Unexpected = ("Err-00003", 500, "unexpected io error")
+}
+
+define_errors! {
+    FileNotExists = Missing,
+    AccessDenied = Forbidden,
+    IoUnexpected = Unexpected
+}
+
+map_io_error! {
+    NotFound => FileNotExists,
+    PermissionDenied => AccessDenied,
+    _ => IoUnexpected,
+}
+
+#[test]
+fn dispatches_known_io_error_kinds_to_their_matching_error() {
+    let err = from_io(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"));
+    assert_eq!(err.class, FileNotExists::new().class());
+}
+
+#[test]
+fn falls_back_to_the_wildcard_arm_for_unmapped_kinds() {
+    let err = from_io(std::io::Error::other("whatever"));
+    assert_eq!(err.class, IoUnexpected::new().class());
+}